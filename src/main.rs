@@ -1,14 +1,21 @@
 use std::collections::{HashMap, HashSet};
-use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::channel;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use glob::glob;
-use new_string_template::template::Template;
+use handlebars::{handlebars_helper, Handlebars};
+use notify_debouncer_full::notify::RecursiveMode;
+use notify_debouncer_full::{new_debouncer, DebounceEventResult};
+use rayon::prelude::*;
 use regex::Regex;
 use serde::{de::value::MapDeserializer, Deserialize};
 use serde_derive::{Deserialize, Serialize};
-use unity_rs::UnityError;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
 
 // common types
 
@@ -20,10 +27,29 @@ enum SupportedAssetType {
     Texture2D,
     #[serde(rename = "text")]
     TextAsset,
+    #[serde(rename = "audioclip")]
+    AudioClip,
+    #[serde(rename = "mesh")]
+    Mesh,
+    #[serde(rename = "monobehaviour")]
+    MonoBehaviour,
+    #[serde(rename = "font")]
+    Font,
 }
 
 // types for argument parsing
 
+/// Output format for `Inspect`.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum InspectFormat {
+    /// Human-readable listing (the original behavior).
+    Text,
+    /// A single JSON array of records per file.
+    Json,
+    /// One JSON record per line, streamable for large bundles.
+    Ndjson,
+}
+
 #[derive(Debug, Parser)]
 struct Args {
     #[clap(subcommand)]
@@ -41,10 +67,22 @@ enum ArgsAction {
         incremental: bool,
         #[clap(short = 'c', long = "config")]
         config_file: String,
+        /// Number of bundles to extract concurrently. `1` restores the
+        /// previous deterministic, single-threaded ordering.
+        #[clap(short = 'j', long = "jobs", default_value_t = num_cpus::get())]
+        jobs: usize,
+        /// Keep running after the initial pass and re-extract bundles
+        /// whose source files change on disk.
+        #[clap(short = 'w', long = "watch")]
+        watch: bool,
     },
     Inspect {
         #[clap(short = 's', long = "only-supported")]
         only_supported: bool,
+        /// Output format: human-readable text, a JSON array per file, or
+        /// streamed NDJSON (one record per line).
+        #[clap(short = 'f', long = "format", value_enum, default_value = "text")]
+        format: InspectFormat,
         #[clap(required = true)]
         files: Vec<String>,
     },
@@ -56,7 +94,8 @@ enum ArgsAction {
 struct Config {
     /// The source file glob pattern
     src: String,
-    /// The destination directory
+    /// The destination directory. If relative, resolved against the
+    /// directory containing this config file, not the process's cwd.
     dest: String,
     /// The list of targets
     targets: Vec<ConfigTarget>,
@@ -66,7 +105,12 @@ struct Config {
 struct ConfigTarget {
     /// Type to extract
     r#type: SupportedAssetType,
-    /// The template string to use as path pattern
+    /// The Handlebars template string to use as path pattern. Besides
+    /// `{{name}}`, `{{container}}`, `{{index}}`, `{{bundle_path}}` and
+    /// `{{extension}}` (the extension the matched exporter writes, e.g.
+    /// `png` or `json`), the `lower`, `upper`, `sanitize`, `default`,
+    /// `pad`, `sha1` and `crc32` helpers are available, e.g.
+    /// `{{sanitize (lower name)}}`.
     template: String,
     /// The regex to use to match the path pattern specified in `template`
     r#match: String,
@@ -113,10 +157,137 @@ struct AssetBundleInfo {
     container_name_map: HashMap<i64, String>,
 }
 
-#[derive(Debug)]
-enum AssetMetadata {
-    Supported(SupportedAssetType, String),
-    Unsupported(String),
+/// One object's listing as emitted by `Inspect` in `json`/`ndjson` mode.
+#[derive(Debug, Serialize)]
+struct InspectRecord {
+    file: String,
+    index: usize,
+    class: String,
+    supported: bool,
+    name: String,
+    container: String,
+    path_id: i64,
+    byte_size: u64,
+}
+
+// types for the incremental-extraction manifest
+
+/// One produced destination path and the inputs that produced it, used to
+/// decide whether a re-run can skip re-extracting it and to prune outputs
+/// whose source has disappeared.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+struct ManifestEntry {
+    source_bundle_path: String,
+    bundle_sha256: String,
+    path_id: i64,
+    target_index: usize,
+}
+
+type Manifest = HashMap<String, ManifestEntry>;
+
+/// Result of running a batch of bundles through `extract_bundle`, with
+/// enough bookkeeping for the caller to prune stale outputs without
+/// mistaking a transient per-bundle or per-destination failure for an asset
+/// that's genuinely gone.
+#[derive(Debug, Default)]
+struct BatchResult {
+    manifest: Manifest,
+    /// Bundle paths that were successfully read and parsed this run (may
+    /// still have failed to produce some destinations).
+    processed_bundles: HashSet<String>,
+    /// Destination paths that were computed this run, whether or not their
+    /// dump actually succeeded.
+    attempted_destinations: HashSet<String>,
+}
+
+/// A `ConfigTarget` with its template (registered in the `Handlebars`
+/// registry under this name) and regex pre-compiled once up front so every
+/// worker can reuse them without recompiling per bundle.
+type TargetInstance = (SupportedAssetType, String, Regex, String);
+
+fn sha256_hex(data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data);
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn sha1_hex(data: &[u8]) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(data);
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn crc32_hex(data: &[u8]) -> String {
+    let mut hasher = crc32fast::Hasher::new();
+    hasher.update(data);
+    format!("{:08x}", hasher.finalize())
+}
+
+/// Strips characters illegal in Windows/POSIX filenames so a template can
+/// turn an arbitrary Unity asset name into a safe path component.
+fn sanitize_filename(input: &str) -> String {
+    input
+        .chars()
+        .filter(|c| {
+            !matches!(c, '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*') && !c.is_control()
+        })
+        .collect()
+}
+
+handlebars_helper!(lower_helper: |s: String| s.to_lowercase());
+handlebars_helper!(upper_helper: |s: String| s.to_uppercase());
+handlebars_helper!(sanitize_helper: |s: String| sanitize_filename(&s));
+handlebars_helper!(default_helper: |s: String, fallback: String| if s.is_empty() { fallback } else { s });
+handlebars_helper!(pad_helper: |n: i64, width: i64| format!("{:0width$}", n, width = width.max(0) as usize));
+handlebars_helper!(sha1_helper: |s: String| sha1_hex(s.as_bytes()));
+handlebars_helper!(crc32_helper: |s: String| crc32_hex(s.as_bytes()));
+
+fn new_template_engine() -> Handlebars<'static> {
+    let mut handlebars = Handlebars::new();
+    handlebars.register_helper("lower", Box::new(lower_helper));
+    handlebars.register_helper("upper", Box::new(upper_helper));
+    handlebars.register_helper("sanitize", Box::new(sanitize_helper));
+    handlebars.register_helper("default", Box::new(default_helper));
+    handlebars.register_helper("pad", Box::new(pad_helper));
+    handlebars.register_helper("sha1", Box::new(sha1_helper));
+    handlebars.register_helper("crc32", Box::new(crc32_helper));
+    handlebars
+}
+
+/// Joins `relative` onto `root`, collapsing `.`/`..` lexically and
+/// rejecting any path that would climb back out of `root` instead of
+/// silently writing outside the destination tree.
+fn rebase_dest(root: &Path, relative: &str) -> Result<PathBuf, String> {
+    let mut normalized = PathBuf::new();
+    let mut depth: i32 = 0;
+    for component in Path::new(relative).components() {
+        match component {
+            std::path::Component::ParentDir => {
+                depth -= 1;
+                if depth < 0 {
+                    return Err(format!("path '{}' escapes the destination root", relative));
+                }
+                normalized.pop();
+            }
+            std::path::Component::CurDir => {}
+            std::path::Component::Normal(part) => {
+                depth += 1;
+                normalized.push(part);
+            }
+            std::path::Component::RootDir | std::path::Component::Prefix(_) => {
+                return Err(format!("path '{}' escapes the destination root", relative));
+            }
+        }
+    }
+    Ok(root.join(normalized))
 }
 
 fn collect_asset_bundle_info(
@@ -149,52 +320,588 @@ fn collect_asset_bundle_info(
     Ok(AssetBundleInfo { container_name_map })
 }
 
-fn get_asset_metadata(obj: &unity_rs::Object) -> Result<AssetMetadata, UnityError> {
-    match obj.class() {
-        unity_rs::ClassID::Sprite => {
-            let sprite: unity_rs::classes::Sprite = obj.read()?;
-            Ok(AssetMetadata::Supported(
-                SupportedAssetType::Sprite,
-                sprite.name,
-            ))
+/// One pluggable asset kind: knows which `ClassID` it handles, how to pull
+/// a display name out of a matching object, and how to write that object's
+/// payload to disk. The `Extract` target loop and `Inspect` both dispatch
+/// through a `Vec<Box<dyn AssetExporter>>` instead of a fixed `match`, so
+/// adding an asset kind never touches those call sites.
+/// `collect_asset_bundle_info` does not use the registry; it reads only
+/// `AssetBundle` objects.
+trait AssetExporter: Sync {
+    fn class(&self) -> unity_rs::ClassID;
+    fn kind(&self) -> SupportedAssetType;
+    /// File extension (without the leading dot) this exporter writes for
+    /// `obj`, exposed to templates as `{{extension}}`. Takes the object
+    /// rather than being a constant because some formats (e.g. `Font`)
+    /// share a class ID across multiple on-disk encodings.
+    fn extension(&self, obj: &unity_rs::Object) -> Result<&'static str, Box<dyn std::error::Error>>;
+    fn metadata(&self, obj: &unity_rs::Object) -> Result<String, Box<dyn std::error::Error>>;
+    fn dump(&self, obj: &unity_rs::Object, path: &str) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+struct SpriteExporter;
+
+impl AssetExporter for SpriteExporter {
+    fn class(&self) -> unity_rs::ClassID {
+        unity_rs::ClassID::Sprite
+    }
+
+    fn kind(&self) -> SupportedAssetType {
+        SupportedAssetType::Sprite
+    }
+
+    fn extension(&self, _obj: &unity_rs::Object) -> Result<&'static str, Box<dyn std::error::Error>> {
+        Ok("png")
+    }
+
+    fn metadata(&self, obj: &unity_rs::Object) -> Result<String, Box<dyn std::error::Error>> {
+        let sprite: unity_rs::classes::Sprite = obj.read()?;
+        Ok(sprite.name)
+    }
+
+    fn dump(&self, obj: &unity_rs::Object, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let sprite: unity_rs::classes::Sprite = obj.read()?;
+        sprite.decode_image()?.save(path)?;
+        Ok(())
+    }
+}
+
+struct Texture2DExporter;
+
+impl AssetExporter for Texture2DExporter {
+    fn class(&self) -> unity_rs::ClassID {
+        unity_rs::ClassID::Texture2D
+    }
+
+    fn kind(&self) -> SupportedAssetType {
+        SupportedAssetType::Texture2D
+    }
+
+    fn extension(&self, _obj: &unity_rs::Object) -> Result<&'static str, Box<dyn std::error::Error>> {
+        Ok("png")
+    }
+
+    fn metadata(&self, obj: &unity_rs::Object) -> Result<String, Box<dyn std::error::Error>> {
+        let texture: unity_rs::classes::Texture2D = obj.read()?;
+        Ok(texture.name)
+    }
+
+    fn dump(&self, obj: &unity_rs::Object, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let texture: unity_rs::classes::Texture2D = obj.read()?;
+        texture.decode_image()?.save(path)?;
+        Ok(())
+    }
+}
+
+struct TextAssetExporter;
+
+impl AssetExporter for TextAssetExporter {
+    fn class(&self) -> unity_rs::ClassID {
+        unity_rs::ClassID::TextAsset
+    }
+
+    fn kind(&self) -> SupportedAssetType {
+        SupportedAssetType::TextAsset
+    }
+
+    fn extension(&self, _obj: &unity_rs::Object) -> Result<&'static str, Box<dyn std::error::Error>> {
+        Ok("txt")
+    }
+
+    fn metadata(&self, obj: &unity_rs::Object) -> Result<String, Box<dyn std::error::Error>> {
+        let text: unity_rs::classes::TextAsset = obj.read()?;
+        Ok(text.name)
+    }
+
+    fn dump(&self, obj: &unity_rs::Object, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let text: unity_rs::classes::TextAsset = obj.read()?;
+        std::fs::write(path, text.script)?;
+        Ok(())
+    }
+}
+
+struct AudioClipExporter;
+
+impl AssetExporter for AudioClipExporter {
+    fn class(&self) -> unity_rs::ClassID {
+        unity_rs::ClassID::AudioClip
+    }
+
+    fn kind(&self) -> SupportedAssetType {
+        SupportedAssetType::AudioClip
+    }
+
+    fn extension(&self, _obj: &unity_rs::Object) -> Result<&'static str, Box<dyn std::error::Error>> {
+        Ok("wav")
+    }
+
+    fn metadata(&self, obj: &unity_rs::Object) -> Result<String, Box<dyn std::error::Error>> {
+        let audio: unity_rs::classes::AudioClip = obj.read()?;
+        Ok(audio.name)
+    }
+
+    fn dump(&self, obj: &unity_rs::Object, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let audio: unity_rs::classes::AudioClip = obj.read()?;
+        std::fs::write(path, audio.decode_audio()?)?;
+        Ok(())
+    }
+}
+
+struct MeshExporter;
+
+impl AssetExporter for MeshExporter {
+    fn class(&self) -> unity_rs::ClassID {
+        unity_rs::ClassID::Mesh
+    }
+
+    fn kind(&self) -> SupportedAssetType {
+        SupportedAssetType::Mesh
+    }
+
+    fn extension(&self, _obj: &unity_rs::Object) -> Result<&'static str, Box<dyn std::error::Error>> {
+        Ok("obj")
+    }
+
+    fn metadata(&self, obj: &unity_rs::Object) -> Result<String, Box<dyn std::error::Error>> {
+        let mesh: unity_rs::classes::Mesh = obj.read()?;
+        Ok(mesh.name)
+    }
+
+    fn dump(&self, obj: &unity_rs::Object, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let mesh: unity_rs::classes::Mesh = obj.read()?;
+        std::fs::write(path, mesh_to_obj(&mesh))?;
+        Ok(())
+    }
+}
+
+/// Renders a Unity mesh's vertex/index buffers as a minimal Wavefront OBJ.
+fn mesh_to_obj(mesh: &unity_rs::classes::Mesh) -> String {
+    let mut out = String::new();
+    for vertex in &mesh.vertices {
+        out.push_str(&format!("v {} {} {}\n", vertex[0], vertex[1], vertex[2]));
+    }
+    for triangle in mesh.indices.chunks_exact(3) {
+        out.push_str(&format!(
+            "f {} {} {}\n",
+            triangle[0] + 1,
+            triangle[1] + 1,
+            triangle[2] + 1
+        ));
+    }
+    out
+}
+
+/// Unity's `Font` class stores both TrueType and OpenType data under the
+/// same class ID; tell them apart by the sfnt magic bytes so the extension
+/// matches what's actually written.
+fn font_extension(data: &[u8]) -> &'static str {
+    if data.starts_with(b"OTTO") {
+        "otf"
+    } else {
+        "ttf"
+    }
+}
+
+struct FontExporter;
+
+impl AssetExporter for FontExporter {
+    fn class(&self) -> unity_rs::ClassID {
+        unity_rs::ClassID::Font
+    }
+
+    fn kind(&self) -> SupportedAssetType {
+        SupportedAssetType::Font
+    }
+
+    fn extension(&self, obj: &unity_rs::Object) -> Result<&'static str, Box<dyn std::error::Error>> {
+        let font: unity_rs::classes::Font = obj.read()?;
+        Ok(font_extension(&font.font_data))
+    }
+
+    fn metadata(&self, obj: &unity_rs::Object) -> Result<String, Box<dyn std::error::Error>> {
+        let font: unity_rs::classes::Font = obj.read()?;
+        Ok(font.name)
+    }
+
+    fn dump(&self, obj: &unity_rs::Object, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let font: unity_rs::classes::Font = obj.read()?;
+        std::fs::write(path, font.font_data)?;
+        Ok(())
+    }
+}
+
+struct MonoBehaviourExporter;
+
+impl AssetExporter for MonoBehaviourExporter {
+    fn class(&self) -> unity_rs::ClassID {
+        unity_rs::ClassID::MonoBehaviour
+    }
+
+    fn kind(&self) -> SupportedAssetType {
+        SupportedAssetType::MonoBehaviour
+    }
+
+    fn extension(&self, _obj: &unity_rs::Object) -> Result<&'static str, Box<dyn std::error::Error>> {
+        Ok("json")
+    }
+
+    fn metadata(&self, obj: &unity_rs::Object) -> Result<String, Box<dyn std::error::Error>> {
+        let tree = read_type_tree_json(obj)?;
+        Ok(tree
+            .get("m_Name")
+            .and_then(|name| name.as_str())
+            .unwrap_or_default()
+            .to_string())
+    }
+
+    fn dump(&self, obj: &unity_rs::Object, path: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let tree = read_type_tree_json(obj)?;
+        std::fs::write(path, serde_json::to_string_pretty(&tree)?)?;
+        Ok(())
+    }
+}
+
+/// Reads a `MonoBehaviour`'s type tree and deserializes it straight into a
+/// `serde_json::Value`, reusing the same `MapDeserializer` bridge
+/// `collect_asset_bundle_info` uses to read `AssetBundle`'s type tree.
+fn read_type_tree_json(
+    obj: &unity_rs::Object,
+) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let info = obj.info.read_type_tree()?;
+    Ok(serde_json::Value::deserialize(MapDeserializer::new(
+        info.into_iter(),
+    ))?)
+}
+
+/// The registry of known asset kinds, in declaration order; `Extract` and
+/// `Inspect` both iterate this instead of matching on `ClassID` directly.
+fn exporters() -> Vec<Box<dyn AssetExporter>> {
+    vec![
+        Box::new(SpriteExporter),
+        Box::new(Texture2DExporter),
+        Box::new(TextAssetExporter),
+        Box::new(AudioClipExporter),
+        Box::new(MeshExporter),
+        Box::new(MonoBehaviourExporter),
+        Box::new(FontExporter),
+    ]
+}
+
+fn find_exporter<'a>(
+    exporters: &'a [Box<dyn AssetExporter>],
+    obj: &unity_rs::Object,
+) -> Option<&'a dyn AssetExporter> {
+    exporters
+        .iter()
+        .find(|exporter| exporter.class() == obj.class())
+        .map(|exporter| exporter.as_ref())
+}
+
+/// Extracts a single bundle; safe to call concurrently as long as callers
+/// share `new_manifest` and `output_lock`.
+#[allow(clippy::too_many_arguments)]
+fn extract_bundle(
+    bundle_path: &std::path::Path,
+    config: &Config,
+    handlebars: &Handlebars,
+    targets: &[TargetInstance],
+    exporters: &[Box<dyn AssetExporter>],
+    old_manifest: &Manifest,
+    new_manifest: &Mutex<Manifest>,
+    processed_bundles: &Mutex<HashSet<String>>,
+    attempted_destinations: &Mutex<HashSet<String>>,
+    output_lock: &Mutex<()>,
+    incremental: bool,
+    dry_run: bool,
+) {
+    let str_bundle_path = bundle_path.to_str().unwrap_or_default().replace('\\', "/");
+
+    let mut lines = vec![str_bundle_path.clone()];
+    let mut err_lines = Vec::new();
+
+    let mut env = unity_rs::Env::new();
+    let data = match std::fs::read(bundle_path) {
+        Ok(data) => data,
+        Err(e) => {
+            let _guard = output_lock.lock().unwrap();
+            println!("{}", lines.join("\n"));
+            eprintln!("Failed to read file: {}\n", e);
+            return;
+        }
+    };
+    let bundle_sha256 = incremental.then(|| sha256_hex(&data));
+    if env.load_from_slice(&data).is_err() {
+        let _guard = output_lock.lock().unwrap();
+        println!("{}", lines.join("\n"));
+        eprintln!("Failed to parse asset bundle\n");
+        return;
+    }
+    let container_name_map = match collect_asset_bundle_info(&env) {
+        Ok(info) => info.container_name_map,
+        Err(e) => {
+            let _guard = output_lock.lock().unwrap();
+            println!("{}", lines.join("\n"));
+            eprintln!("Failed to collect asset bundle info: {}\n", e);
+            return;
+        }
+    };
+
+    // Reached only once the bundle itself has been read and parsed, so a
+    // pruning pass can tell "this bundle's output is genuinely gone" apart
+    // from "this bundle failed to read/parse this run".
+    if incremental {
+        processed_bundles
+            .lock()
+            .unwrap()
+            .insert(str_bundle_path.clone());
+    }
+
+    let mut local_entries = Vec::new();
+    let mut local_attempted = Vec::new();
+
+    for (index, obj) in env.objects().enumerate() {
+        let exporter = match find_exporter(exporters, &obj) {
+            Some(exporter) => exporter,
+            None => continue,
+        };
+        let name = match exporter.metadata(&obj) {
+            Ok(name) => name,
+            Err(e) => {
+                err_lines.push(format!("Failed to read object: {}\n", e));
+                continue;
+            }
+        };
+        let r#type = exporter.kind();
+        let extension = match exporter.extension(&obj) {
+            Ok(extension) => extension,
+            Err(e) => {
+                err_lines.push(format!("Failed to determine extension: {}\n", e));
+                continue;
+            }
+        };
+
+        let data = serde_json::json!({
+            "name": name,
+            "container": container_name_map
+                .get(&obj.info.path_id)
+                .cloned()
+                .unwrap_or_default(),
+            "index": index,
+            "bundle_path": str_bundle_path,
+            "extension": extension,
+        });
+
+        for (target_index, (target_type, template_name, path_regex, path_replacement)) in
+            targets.iter().enumerate()
+        {
+            if *target_type != r#type {
+                continue;
+            }
+
+            let rendered = match handlebars.render(template_name, &data) {
+                Ok(rendered) => rendered,
+                Err(e) => {
+                    err_lines.push(format!("Failed to render template: {}\n", e));
+                    continue;
+                }
+            };
+            if !path_regex.is_match(&rendered) {
+                continue;
+            }
+
+            let replaced = path_regex.replace(&rendered, path_replacement);
+            let path = match rebase_dest(Path::new(&config.dest), replaced.as_ref()) {
+                Ok(path) => path,
+                Err(e) => {
+                    err_lines.push(format!(
+                        "Failed to compute destination for '{}': {}\n",
+                        rendered, e
+                    ));
+                    continue;
+                }
+            };
+            let str_path = path.to_str().unwrap_or_default().replace('\\', "/");
+
+            if incremental {
+                local_attempted.push(str_path.clone());
+            }
+
+            lines.push(format!("  {}", str_path));
+
+            let manifest_entry = bundle_sha256.as_ref().map(|bundle_sha256| ManifestEntry {
+                source_bundle_path: str_bundle_path.clone(),
+                bundle_sha256: bundle_sha256.clone(),
+                path_id: obj.info.path_id,
+                target_index,
+            });
+
+            if let Some(entry) = &manifest_entry {
+                if old_manifest.get(&str_path) == Some(entry) {
+                    local_entries.push((str_path.clone(), entry.clone()));
+                    lines.push("    (up to date)".to_string());
+                    continue;
+                }
+            }
+
+            let mut dump_failed = false;
+            if !dry_run {
+                match path.parent() {
+                    Some(parent) => {
+                        if !parent.exists() {
+                            if let Err(e) = std::fs::create_dir_all(parent) {
+                                if e.kind() != std::io::ErrorKind::AlreadyExists {
+                                    err_lines.push(format!(
+                                        "Failed to create directory {}: {}\n",
+                                        parent.display(),
+                                        e
+                                    ));
+                                    dump_failed = true;
+                                }
+                            }
+                        }
+                    }
+                    None => {
+                        err_lines.push(format!("Failed to get parent path of {}", str_path));
+                        dump_failed = true;
+                    }
+                }
+                if !dump_failed {
+                    if let Err(e) = exporter.dump(&obj, &str_path) {
+                        err_lines.push(format!("Failed to dump asset to {}: {}\n", str_path, e));
+                        dump_failed = true;
+                    }
+                }
+            }
+
+            if let Some(entry) = manifest_entry {
+                if !dump_failed {
+                    local_entries.push((str_path.clone(), entry));
+                }
+            }
         }
-        unity_rs::ClassID::Texture2D => {
-            let texture: unity_rs::classes::Texture2D = obj.read()?;
-            Ok(AssetMetadata::Supported(
-                SupportedAssetType::Texture2D,
-                texture.name,
-            ))
+    }
+
+    {
+        let _guard = output_lock.lock().unwrap();
+        println!("{}", lines.join("\n"));
+        for err_line in &err_lines {
+            eprintln!("{}", err_line);
         }
-        unity_rs::ClassID::TextAsset => {
-            let text: unity_rs::classes::TextAsset = obj.read()?;
-            Ok(AssetMetadata::Supported(
-                SupportedAssetType::TextAsset,
-                text.name,
-            ))
+    }
+
+    if incremental {
+        attempted_destinations
+            .lock()
+            .unwrap()
+            .extend(local_attempted);
+        new_manifest.lock().unwrap().extend(local_entries);
+    }
+}
+
+/// Deepest directory prefix of a glob pattern with no glob metacharacters,
+/// i.e. the tree a filesystem watcher needs to cover.
+fn glob_base_dir(pattern: &str) -> PathBuf {
+    let mut base = PathBuf::new();
+    for component in Path::new(pattern).components() {
+        if component
+            .as_os_str()
+            .to_string_lossy()
+            .contains(['*', '?', '[', '{'])
+        {
+            break;
         }
-        _ => Ok(AssetMetadata::Unsupported(format!(
-            "{:?} (unsupported)",
-            obj.class()
-        ))),
+        base.push(component);
+    }
+    if base.as_os_str().is_empty() {
+        PathBuf::from(".")
+    } else {
+        base
     }
 }
 
-fn dump_asset(path: &str, obj: &unity_rs::Object) -> Result<(), Box<dyn std::error::Error>> {
-    match obj.class() {
-        unity_rs::ClassID::Sprite => {
-            let sprite: unity_rs::classes::Sprite = obj.read()?;
-            sprite.decode_image()?.save(path)?;
+/// Re-runs `run_batch` for matched bundles touched by each debounced batch
+/// of filesystem events under `config.src`, until Ctrl-C sets `cancel`.
+fn watch_and_rebuild(
+    config: &Config,
+    cancel: &AtomicBool,
+    manifest_filename: &Option<String>,
+    current_manifest: &mut Manifest,
+    incremental: bool,
+    run_batch: impl Fn(&[PathBuf], &Manifest) -> BatchResult,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let watch_root = glob_base_dir(&config.src);
+    let (watch_tx, watch_rx) = channel();
+    let mut debouncer = new_debouncer(
+        Duration::from_millis(500),
+        None,
+        move |result: DebounceEventResult| {
+            if let Ok(events) = result {
+                let _ = watch_tx.send(events);
+            }
+        },
+    )?;
+    debouncer
+        .watcher()
+        .watch(&watch_root, RecursiveMode::Recursive)?;
+
+    println!(
+        "Watching {} for changes... (Ctrl-C to exit)",
+        watch_root.display()
+    );
+
+    while !cancel.load(Ordering::SeqCst) {
+        let events = match watch_rx.recv_timeout(Duration::from_millis(200)) {
+            Ok(events) => events,
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => continue,
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break,
+        };
+
+        // Canonicalize rather than compare raw strings: notify's recursive
+        // watch commonly hands back paths in a different absolute/relative
+        // form than `glob(&config.src)` does, so a plain string match can
+        // silently never hit.
+        let changed_paths: HashSet<PathBuf> = events
+            .iter()
+            .filter(|event| event.kind.is_create() || event.kind.is_modify())
+            .flat_map(|event| event.paths.iter())
+            .filter_map(|path| std::fs::canonicalize(path).ok())
+            .collect();
+        if changed_paths.is_empty() {
+            continue;
         }
-        unity_rs::ClassID::Texture2D => {
-            let texture: unity_rs::classes::Texture2D = obj.read()?;
-            texture.decode_image()?.save(path)?;
+
+        let rebuild_paths: Vec<PathBuf> = glob(&config.src)
+            .unwrap_or_else(|_| panic!("Failed to glob: {}", &config.src))
+            .flatten()
+            .filter(|path| {
+                std::fs::canonicalize(path)
+                    .map(|path| changed_paths.contains(&path))
+                    .unwrap_or(false)
+            })
+            .collect();
+        if rebuild_paths.is_empty() {
+            continue;
         }
-        unity_rs::ClassID::TextAsset => {
-            let text: unity_rs::classes::TextAsset = obj.read()?;
-            std::fs::write(path, text.script)?;
+
+        let start = Instant::now();
+        let rebuilt = run_batch(&rebuild_paths, current_manifest);
+        let rebuilt_count = rebuilt.manifest.len();
+        current_manifest.extend(rebuilt.manifest);
+
+        if incremental {
+            if let Some(filename) = manifest_filename {
+                std::fs::write(filename, serde_json::to_string_pretty(current_manifest)?)?;
+            }
         }
-        _ => Err(UnityError::Unimplemented)?,
+
+        println!(
+            "Rebuilt {} bundle(s), {} output(s) in {:.2?}",
+            rebuild_paths.len(),
+            rebuilt_count,
+            start.elapsed()
+        );
     }
+
     Ok(())
 }
 
@@ -205,8 +912,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             chdir,
             dry_run,
             incremental,
+            jobs,
+            watch,
         } => {
-            let config: Config =
+            let mut config: Config =
                 match toml::from_str(&match std::fs::read_to_string(&config_file) {
                     Ok(data) => data,
                     Err(e) => {
@@ -221,184 +930,226 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     }
                 };
 
-            // config.toml -> config_progress.txt
-            let incremental_progress_filename: Option<String> = if incremental {
-                Some(format!(
-                    "{}_progress.txt",
-                    std::path::Path::new(&config_file)
-                        .file_stem()
-                        .unwrap_or_default()
-                        .to_str()
-                        .unwrap_or_default()
-                ))
+            // The config file's directory, canonicalized so it stays a valid,
+            // cwd-independent anchor even after `--chdir` changes the
+            // process's cwd below.
+            let config_dir = {
+                let parent = std::path::Path::new(&config_file)
+                    .parent()
+                    .filter(|p| !p.as_os_str().is_empty())
+                    .unwrap_or_else(|| std::path::Path::new("."));
+                std::fs::canonicalize(parent).unwrap_or_else(|_| parent.to_path_buf())
+            };
+
+            // `dest` is always resolved against the config file's own
+            // directory, so extraction behaves the same whether or not
+            // `--chdir` was passed.
+            if !std::path::Path::new(&config.dest).is_absolute() {
+                config.dest = config_dir.join(&config.dest).to_string_lossy().into_owned();
+            }
+
+            // config.toml -> config_manifest.json, resolved against
+            // `config_dir` so the manifest is read from and written to the
+            // same place regardless of `--chdir`.
+            let manifest_filename: Option<String> = if incremental {
+                Some(
+                    config_dir
+                        .join(format!(
+                            "{}_manifest.json",
+                            std::path::Path::new(&config_file)
+                                .file_stem()
+                                .unwrap_or_default()
+                                .to_str()
+                                .unwrap_or_default()
+                        ))
+                        .to_string_lossy()
+                        .into_owned(),
+                )
             } else {
                 None
             };
 
-            let processed_files: HashSet<String> = match &incremental_progress_filename {
+            let old_manifest: Manifest = match &manifest_filename {
                 Some(filename) => std::fs::read_to_string(filename)
-                    .unwrap_or_default()
-                    .lines()
-                    .map(|line| line.to_string())
-                    .collect(),
-                None => HashSet::new(),
+                    .ok()
+                    .and_then(|data| serde_json::from_str(&data).ok())
+                    .unwrap_or_default(),
+                None => Manifest::new(),
             };
 
-            // Open progress file with append mode
-            let mut incremental_progress_file = incremental_progress_filename.map(|filename| {
-                std::fs::OpenOptions::new()
-                    .create(true)
-                    .append(true)
-                    .open(filename)
-                    .unwrap()
-            });
-
             if chdir {
-                std::env::set_current_dir(
-                    std::path::Path::new(&config_file)
-                        .parent()
-                        .expect("Failed to extract parent path from config filename"),
-                )
-                .expect("Failed to set current directory");
+                std::env::set_current_dir(&config_dir).expect("Failed to set current directory");
             }
 
+            let mut handlebars = new_template_engine();
             let targets_instantiated = config
                 .targets
                 .iter()
-                .map(|target| {
-                    let path_pattern = Template::new(&target.template);
+                .enumerate()
+                .map(|(target_index, target)| {
+                    let template_name = format!("target{}", target_index);
+                    handlebars
+                        .register_template_string(&template_name, &target.template)
+                        .unwrap_or_else(|e| panic!("Failed to compile template: {}", e));
                     let path_regex = Regex::new(&target.r#match)
                         .unwrap_or_else(|_| panic!("Failed to compile regex: {}", target.r#match));
                     let path_replacement = target.dest.clone();
-                    (target.r#type, path_pattern, path_regex, path_replacement)
+                    (target.r#type, template_name, path_regex, path_replacement)
                 })
                 .collect::<Vec<_>>();
+            let handlebars = handlebars;
+            let exporters = exporters();
 
+            let cancel = Arc::new(AtomicBool::new(false));
             let (tx, rx) = channel();
             ctrlc::set_handler(move || tx.send(()).expect("Could not send signal on channel."))
                 .expect("Error setting Ctrl-C handler");
+            {
+                let cancel = cancel.clone();
+                std::thread::spawn(move || {
+                    if rx.recv().is_ok() {
+                        eprintln!("Interrupted");
+                        cancel.store(true, Ordering::SeqCst);
+                    }
+                });
+            }
 
-            for bundle_path in glob(&config.src)
+            let bundle_paths = glob(&config.src)
                 .unwrap_or_else(|_| panic!("Failed to glob: {}", &config.src))
                 .flatten()
-            {
-                if rx.try_recv().is_ok() {
-                    eprintln!("Interrupted");
-                    break;
-                }
+                .collect::<Vec<_>>();
+            let matched_bundle_paths: HashSet<String> = bundle_paths
+                .iter()
+                .filter_map(|path| path.to_str())
+                .map(|path| path.replace('\\', "/"))
+                .collect();
 
-                let str_bundle_path = bundle_path
-                    .as_path()
-                    .to_str()
-                    .unwrap_or_default()
-                    .replace('\\', "/");
-                let should_skip = processed_files.contains(&str_bundle_path);
-                println!(
-                    "{}{}",
-                    str_bundle_path,
-                    if should_skip { " (skipped)" } else { "" }
-                );
-
-                if should_skip {
-                    continue;
-                }
+            let output_lock = Mutex::new(());
 
-                let mut env = unity_rs::Env::new();
-                let data = match std::fs::read(&bundle_path) {
-                    Ok(data) => data,
-                    Err(e) => {
-                        eprintln!("Failed to read file: {}\n", e);
-                        continue;
+            // Dispatches `paths` across the worker pool; shared by the
+            // initial full pass and by watch-triggered rebuilds.
+            let run_batch = |paths: &[std::path::PathBuf], baseline: &Manifest| -> BatchResult {
+                let new_manifest = Mutex::new(Manifest::new());
+                let processed_bundles = Mutex::new(HashSet::new());
+                let attempted_destinations = Mutex::new(HashSet::new());
+                let run_one = |bundle_path: &std::path::PathBuf| {
+                    if cancel.load(Ordering::SeqCst) {
+                        return;
                     }
+                    extract_bundle(
+                        bundle_path,
+                        &config,
+                        &handlebars,
+                        &targets_instantiated,
+                        &exporters,
+                        baseline,
+                        &new_manifest,
+                        &processed_bundles,
+                        &attempted_destinations,
+                        &output_lock,
+                        incremental,
+                        dry_run,
+                    );
                 };
-                if env.load_from_slice(&data).is_err() {
-                    eprintln!("Failed to parse asset bundle\n");
-                    continue;
+
+                if jobs <= 1 {
+                    paths.iter().for_each(run_one);
+                } else {
+                    rayon::ThreadPoolBuilder::new()
+                        .num_threads(jobs)
+                        .build()
+                        .expect("Failed to build worker pool")
+                        .install(|| {
+                            paths.par_iter().for_each(run_one);
+                        });
                 }
-                let container_name_map = match collect_asset_bundle_info(&env) {
-                    Ok(info) => info.container_name_map,
-                    Err(e) => {
-                        eprintln!("Failed to collect asset bundle info: {}\n", e);
-                        continue;
-                    }
-                };
-                for (index, obj) in env.objects().enumerate() {
-                    let (r#type, name) = match get_asset_metadata(&obj) {
-                        Ok(AssetMetadata::Supported(r#type, name)) => (r#type, name),
-                        Ok(AssetMetadata::Unsupported(_)) => {
-                            continue;
-                        }
-                        Err(e) => {
-                            eprintln!("Failed to read object: {}\n", e);
-                            continue;
-                        }
-                    };
 
-                    let mut placeholder_map = HashMap::new();
-                    placeholder_map.insert("name", name);
-                    placeholder_map.insert(
-                        "container",
-                        container_name_map
-                            .get(&obj.info.path_id)
-                            .cloned()
-                            .unwrap_or_default(),
-                    );
-                    placeholder_map.insert("index", index.to_string());
-                    placeholder_map.insert("bundle_path", str_bundle_path.clone());
+                BatchResult {
+                    manifest: new_manifest.into_inner().unwrap(),
+                    processed_bundles: processed_bundles.into_inner().unwrap(),
+                    attempted_destinations: attempted_destinations.into_inner().unwrap(),
+                }
+            };
 
-                    for (target_type, path_pattern, path_regex, path_replacement) in
-                        &targets_instantiated
-                    {
-                        if *target_type != r#type {
-                            continue;
-                        }
+            let BatchResult {
+                manifest: initial_manifest,
+                processed_bundles,
+                attempted_destinations,
+            } = run_batch(&bundle_paths, &old_manifest);
+            let interrupted = cancel.load(Ordering::SeqCst);
 
-                        let rendered = path_pattern.render_nofail(&placeholder_map);
-                        if !path_regex.is_match(&rendered) {
+            if let Some(filename) = &manifest_filename {
+                if !interrupted && !dry_run {
+                    for (old_path, old_entry) in &old_manifest {
+                        let still_produced = initial_manifest.get(old_path).is_some_and(|entry| {
+                            entry.source_bundle_path == old_entry.source_bundle_path
+                        });
+                        if still_produced {
                             continue;
                         }
-
-                        let path = path_regex.replace(&rendered, path_replacement);
-                        let path = std::path::Path::new(&config.dest)
-                            .join(std::path::Path::new(path.as_ref()));
-                        let str_path = path.to_str().unwrap_or_default();
-
-                        println!("  {}", str_path.replace('\\', "/"));
-                        if !dry_run {
-                            match path.parent() {
-                                Some(parent) => {
-                                    if !parent.exists() {
-                                        std::fs::create_dir_all(parent)?;
-                                    }
-                                }
-                                None => {
-                                    eprintln!("Failed to get parent path of {}", str_path);
+                        // Distinguish "genuinely gone" from "merely failed
+                        // this run": a bundle no longer matched by the glob
+                        // has truly disappeared, but one that matched and
+                        // still exists yet failed to read/parse, or a
+                        // destination whose dump simply errored this run,
+                        // must not take its still-good output down with it.
+                        let bundle_in_glob =
+                            matched_bundle_paths.contains(&old_entry.source_bundle_path);
+                        let bundle_processed =
+                            processed_bundles.contains(&old_entry.source_bundle_path);
+                        let attempted_this_run = attempted_destinations.contains(old_path);
+                        let stale = if !bundle_in_glob {
+                            true
+                        } else if bundle_processed {
+                            !attempted_this_run
+                        } else {
+                            false
+                        };
+                        if stale {
+                            if let Err(e) = std::fs::remove_file(old_path) {
+                                if e.kind() != std::io::ErrorKind::NotFound {
+                                    eprintln!("Failed to prune stale output {}: {}\n", old_path, e);
                                 }
                             }
-                            if let Err(e) = dump_asset(str_path, &obj) {
-                                eprintln!("Failed to dump asset to {}: {}\n", str_path, e);
-                            }
                         }
                     }
-                }
 
-                if let Some(file) = &mut incremental_progress_file {
-                    writeln!(file, "{}", str_bundle_path)?;
-                    file.flush()?;
+                    std::fs::write(filename, serde_json::to_string_pretty(&initial_manifest)?)?;
                 }
             }
 
             println!("Done");
+
+            let mut current_manifest = old_manifest;
+            current_manifest.extend(initial_manifest);
+
+            if watch && !interrupted {
+                watch_and_rebuild(
+                    &config,
+                    &cancel,
+                    &manifest_filename,
+                    &mut current_manifest,
+                    incremental,
+                    run_batch,
+                )?;
+            }
         }
         ArgsAction::Inspect {
             only_supported,
+            format,
             files,
         } => {
+            let exporters = exporters();
+            let is_text = matches!(format, InspectFormat::Text);
+
             for file in files {
-                println!("{}", file.replace('\\', "/"));
+                let str_file = file.replace('\\', "/");
+                if is_text {
+                    println!("{}", str_file);
+                }
 
                 let mut env = unity_rs::Env::new();
-                let data = match std::fs::read(file) {
+                let data = match std::fs::read(&file) {
                     Ok(data) => data,
                     Err(e) => {
                         eprintln!("  Failed to read file: {}\n", e);
@@ -416,42 +1167,145 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                         continue;
                     }
                 };
+
+                let mut records = Vec::new();
+
                 for (index, obj) in env.objects().enumerate() {
-                    let (supported, str_type, name) = match get_asset_metadata(&obj) {
-                        Ok(AssetMetadata::Supported(r#type, name)) => (
-                            true,
-                            serde_json::to_string(&r#type)
-                                .unwrap_or_default()
-                                .replace('"', ""),
-                            name,
-                        ),
-                        Ok(AssetMetadata::Unsupported(str_type)) => {
-                            (false, str_type, String::new())
-                        }
-                        Err(e) => {
-                            eprintln!("Failed to read object: {}\n", e);
-                            continue;
+                    let (supported, str_type, name) = match find_exporter(&exporters, &obj) {
+                        Some(exporter) => {
+                            let name = match exporter.metadata(&obj) {
+                                Ok(name) => name,
+                                Err(e) => {
+                                    eprintln!("Failed to read object: {}\n", e);
+                                    continue;
+                                }
+                            };
+                            (
+                                true,
+                                serde_json::to_string(&exporter.kind())
+                                    .unwrap_or_default()
+                                    .replace('"', ""),
+                                name,
+                            )
                         }
+                        None => (false, format!("{:?}", obj.class()), String::new()),
                     };
 
                     if only_supported && !supported {
                         continue;
                     }
 
-                    println!("  #{}: {}", index, str_type);
-                    println!("    name: {}", name);
+                    let container = container_name_map
+                        .get(&obj.info.path_id)
+                        .cloned()
+                        .unwrap_or_default();
+
+                    match format {
+                        InspectFormat::Text => {
+                            if supported {
+                                println!("  #{}: {}", index, str_type);
+                            } else {
+                                println!("  #{}: {} (unsupported)", index, str_type);
+                            }
+                            println!("    name: {}", name);
+                            println!("    container: {}", container);
+                        }
+                        InspectFormat::Json => {
+                            records.push(InspectRecord {
+                                file: str_file.clone(),
+                                index,
+                                class: str_type,
+                                supported,
+                                name,
+                                container,
+                                path_id: obj.info.path_id,
+                                byte_size: obj.info.byte_size as u64,
+                            });
+                        }
+                        InspectFormat::Ndjson => {
+                            let record = InspectRecord {
+                                file: str_file.clone(),
+                                index,
+                                class: str_type,
+                                supported,
+                                name,
+                                container,
+                                path_id: obj.info.path_id,
+                                byte_size: obj.info.byte_size as u64,
+                            };
+                            println!("{}", serde_json::to_string(&record).unwrap_or_default());
+                        }
+                    }
+                }
+
+                if matches!(format, InspectFormat::Json) {
                     println!(
-                        "    container: {}",
-                        container_name_map
-                            .get(&obj.info.path_id)
-                            .unwrap_or(&String::new())
+                        "{}",
+                        serde_json::to_string_pretty(&records).unwrap_or_default()
                     );
                 }
             }
 
-            println!("Done");
+            if is_text {
+                println!("Done");
+            }
         }
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rebase_dest_joins_plain_relative_paths() {
+        let root = Path::new("/out");
+        assert_eq!(
+            rebase_dest(root, "a/b.png").unwrap(),
+            PathBuf::from("/out/a/b.png")
+        );
+    }
+
+    #[test]
+    fn rebase_dest_collapses_parent_dirs_that_stay_inside_root() {
+        let root = Path::new("/out");
+        assert_eq!(
+            rebase_dest(root, "a/../b.png").unwrap(),
+            PathBuf::from("/out/b.png")
+        );
+        assert_eq!(
+            rebase_dest(root, "a/b/../../c.png").unwrap(),
+            PathBuf::from("/out/c.png")
+        );
+    }
+
+    #[test]
+    fn rebase_dest_rejects_parent_dirs_that_escape_root() {
+        assert!(rebase_dest(Path::new("/out"), "../escape.png").is_err());
+        assert!(rebase_dest(Path::new("/out"), "a/../../escape.png").is_err());
+    }
+
+    #[test]
+    fn rebase_dest_rejects_absolute_paths() {
+        assert!(rebase_dest(Path::new("/out"), "/etc/passwd").is_err());
+    }
+
+    // `Component::Prefix` (drive letters, UNC roots) is only ever produced
+    // by `Path::components()` on Windows; on other platforms a leading
+    // `C:\` is just an ordinary path segment.
+    #[test]
+    #[cfg(windows)]
+    fn rebase_dest_rejects_windows_prefixed_paths() {
+        assert!(rebase_dest(Path::new(r"C:\out"), r"C:\windows\system32").is_err());
+    }
+
+    #[test]
+    fn rebase_dest_allows_empty_relative_path() {
+        assert_eq!(
+            rebase_dest(Path::new("/out"), "").unwrap(),
+            PathBuf::from("/out")
+        );
+    }
+}